@@ -0,0 +1,117 @@
+//! User config file support: custom theme palettes and info field layout.
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::Theme;
+
+/// An info row `print_info` knows how to render. The order fields appear in
+/// a user config's `fields` list is the order they're rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Field {
+    Os,
+    Kernel,
+    Uptime,
+    Shell,
+    Cpu,
+    Memory,
+    Disk,
+    Temp,
+    Network,
+    Palette,
+}
+
+impl Field {
+    /// Layout used when the user hasn't configured their own `fields` list.
+    pub const DEFAULT_ORDER: &'static [Field] = &[
+        Field::Os,
+        Field::Kernel,
+        Field::Uptime,
+        Field::Shell,
+        Field::Cpu,
+        Field::Memory,
+        Field::Disk,
+        Field::Temp,
+        Field::Network,
+        Field::Palette,
+    ];
+}
+
+/// RGB overrides for a [`Theme`]. Any channel left unset keeps the base
+/// theme's color.
+#[derive(Debug, Deserialize, Default)]
+pub struct ThemeConfig {
+    pub primary: Option<[u8; 3]>,
+    pub secondary: Option<[u8; 3]>,
+    pub accent: Option<[u8; 3]>,
+    pub info: Option<[u8; 3]>,
+}
+
+impl ThemeConfig {
+    /// Layer these overrides on top of a base theme.
+    pub fn apply(&self, base: Theme) -> Theme {
+        Theme {
+            primary: self.primary.map(to_color).unwrap_or(base.primary),
+            secondary: self.secondary.map(to_color).unwrap_or(base.secondary),
+            accent: self.accent.map(to_color).unwrap_or(base.accent),
+            info: self.info.map(to_color).unwrap_or(base.info),
+        }
+    }
+}
+
+fn to_color([r, g, b]: [u8; 3]) -> colored::Color {
+    colored::Color::TrueColor { r, g, b }
+}
+
+/// `$XDG_CONFIG_HOME/ferris-fetch/config.{toml,json}` (`%APPDATA%` on
+/// Windows), deserialized.
+#[derive(Debug, Deserialize, Default)]
+pub struct UserConfig {
+    #[serde(default)]
+    pub fields: Option<Vec<Field>>,
+    #[serde(default)]
+    pub palette: Option<ThemeConfig>,
+}
+
+impl UserConfig {
+    /// Resolve the config file path: an explicit `--config` override takes
+    /// priority, otherwise the default XDG/APPDATA location, trying `.toml`
+    /// then `.json`. Returns `None` if no override was given and no default
+    /// file exists.
+    pub fn locate(override_path: Option<&str>) -> Option<PathBuf> {
+        if let Some(path) = override_path {
+            return Some(PathBuf::from(path));
+        }
+
+        let dir = config_dir()?.join("ferris-fetch");
+        ["toml", "json"]
+            .iter()
+            .map(|ext| dir.join(format!("config.{ext}")))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Load and parse a config file, guessing the format from its
+    /// extension and falling back to TOML.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&contents)
+                .map_err(|err| format!("failed to parse {}: {err}", path.display())),
+            _ => toml::from_str(&contents)
+                .map_err(|err| format!("failed to parse {}: {err}", path.display())),
+        }
+    }
+}
+
+fn config_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    }
+}
@@ -5,10 +5,25 @@ use crossterm::execute;
 use image::{DynamicImage, RgbaImage};
 use resvg::{render, tiny_skia, usvg};
 use std::env;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
 use sysinfo::System;
 use tiny_skia::{Pixmap, Transform};
 
+mod config;
+use config::{Field, UserConfig};
+
+/// When to emit colored output
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ColorMode {
+    /// Color when stdout is an interactive terminal
+    Auto,
+    /// Always emit color
+    Always,
+    /// Never emit color
+    Never,
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
@@ -16,8 +31,12 @@ struct Args {
     #[arg(short, long, default_value = "rust")]
     theme: String,
 
-    /// Disable colored output
-    #[arg(long)]
+    /// When to use colored output
+    #[arg(long, value_enum, default_value = "auto")]
+    color: ColorMode,
+
+    /// Disable colored output (deprecated, use --color=never)
+    #[arg(long, hide = true)]
     no_color: bool,
 
     /// Show minimal info only
@@ -27,18 +46,73 @@ struct Args {
     /// Hide ASCII art
     #[arg(long)]
     no_art: bool,
+
+    /// Path to a config file (overrides the default XDG/APPDATA location)
+    #[arg(long)]
+    config: Option<String>,
+
+    /// Show a progress bar per mounted disk instead of one aggregate total
+    #[arg(long)]
+    disks: bool,
+
+    /// Re-render on a timer instead of printing once (interval in ms, default 1000)
+    #[arg(long, num_args = 0..=1, default_missing_value = "1000")]
+    watch: Option<u64>,
+
+    /// Show a CPU-labeled sensor temperature (or the hottest sensor if none is labeled CPU)
+    #[arg(long)]
+    temps: bool,
+
+    /// Show total bytes received/transmitted across all network interfaces
+    #[arg(long)]
+    network: bool,
+
+    /// Graphics protocol to render Ferris with
+    #[arg(long, value_enum, default_value = "auto")]
+    image_protocol: ImageProtocol,
+}
+
+/// Resolve whether color should be disabled, honoring `--color`, the deprecated
+/// `--no-color` alias, and the `NO_COLOR`/`CLICOLOR_FORCE` environment variables.
+fn resolve_no_color(args: &Args) -> bool {
+    if args.no_color {
+        return true;
+    }
+
+    match args.color {
+        ColorMode::Always => false,
+        ColorMode::Never => true,
+        ColorMode::Auto => {
+            if env::var_os("NO_COLOR").is_some() {
+                true
+            } else if env::var_os("CLICOLOR_FORCE").is_some() {
+                false
+            } else {
+                !io::stdout().is_terminal()
+            }
+        }
+    }
 }
 
 /// Color theme configuration
 #[derive(Clone)]
-struct Theme {
-    primary: Color,
-    secondary: Color,
-    accent: Color,
-    info: Color,
+pub(crate) struct Theme {
+    pub(crate) primary: Color,
+    pub(crate) secondary: Color,
+    pub(crate) accent: Color,
+    pub(crate) info: Color,
 }
 
 impl Theme {
+    /// Resolve one of the built-in named themes, falling back to "rust" for
+    /// anything unrecognized.
+    ///
+    /// This match intentionally stays a small, fixed set of bases rather than
+    /// growing into a dynamic registry: the extensibility the config
+    /// subsystem needs is arbitrary custom palettes, which is already covered
+    /// by [`ThemeConfig::apply`](crate::config::ThemeConfig::apply) layering
+    /// RGB overrides on top of the base theme selected here. An explicit
+    /// `--theme` on the command line skips those overrides.
     fn get(name: &str) -> Self {
         match name.to_lowercase().as_str() {
             "ocean" => Theme {
@@ -119,16 +193,36 @@ struct SysInfo {
     cpu_cores: usize,
     memory_used: u64,
     memory_total: u64,
-    #[allow(dead_code)]
     disk_used: u64,
-    #[allow(dead_code)]
     disk_total: u64,
+    disks: Vec<DiskEntry>,
+    /// Live CPU utilization, sampled with a two-pass refresh.
+    cpu_usage: f32,
+    /// CPU-labeled (or else hottest) sensor reading, when `--temps` is passed.
+    temperature_c: Option<f32>,
+    /// Total bytes received/transmitted across all interfaces, when
+    /// `--network` is passed.
+    network: Option<(u64, u64)>,
+}
+
+/// A single mounted disk, as reported by `sysinfo::Disks`.
+struct DiskEntry {
+    mount_point: String,
+    filesystem: String,
+    used: u64,
+    total: u64,
 }
 
 impl SysInfo {
-    fn collect() -> Self {
+    /// A single `refresh_all()`'s `cpu_usage()` is a meaningless constant —
+    /// `sysinfo` needs two refreshes spaced apart to measure real load — so
+    /// this always pays the `MINIMUM_CPU_UPDATE_INTERVAL` sampling cost,
+    /// including for one-shot runs, rather than showing a number that looks
+    /// real but isn't.
+    fn collect(include_temps: bool, include_network: bool) -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
+        let cpu_usage = sample_cpu_usage(&mut sys);
 
         // Get OS info
         let os_info = os_info::get();
@@ -164,14 +258,52 @@ impl SysInfo {
         let memory_total = sys.total_memory();
         let memory_used = sys.used_memory();
 
-        // Get disk info
+        // Get disk info, both the aggregate total and the per-mount breakdown
         let mut disk_total = 0u64;
         let mut disk_used = 0u64;
+        let mut disks = Vec::new();
         for disk in sysinfo::Disks::new_with_refreshed_list().iter() {
-            disk_total += disk.total_space();
-            disk_used += disk.total_space() - disk.available_space();
+            let total = disk.total_space();
+            let used = total - disk.available_space();
+            disk_total += total;
+            disk_used += used;
+            disks.push(DiskEntry {
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                filesystem: disk.file_system().to_string_lossy().to_string(),
+                used,
+                total,
+            });
         }
 
+        // CPU-labeled (or else hottest) sensor, only probed when requested
+        let temperature_c = if include_temps {
+            let components = sysinfo::Components::new_with_refreshed_list();
+            components
+                .iter()
+                .find(|c| c.label().to_lowercase().contains("cpu"))
+                .or_else(|| {
+                    components.iter().max_by(|a, b| {
+                        a.temperature()
+                            .partial_cmp(&b.temperature())
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                })
+                .map(|c| c.temperature())
+        } else {
+            None
+        };
+
+        // Total bytes across all interfaces, only probed when requested
+        let network = if include_network {
+            let networks = sysinfo::Networks::new_with_refreshed_list();
+            let totals = networks.iter().fold((0u64, 0u64), |(rx, tx), (_, data)| {
+                (rx + data.total_received(), tx + data.total_transmitted())
+            });
+            Some(totals)
+        } else {
+            None
+        };
+
         SysInfo {
             hostname: System::host_name().unwrap_or_else(|| "Unknown".to_string()),
             username: whoami::username().unwrap_or_else(|_| "Unknown".to_string()),
@@ -185,10 +317,23 @@ impl SysInfo {
             memory_total,
             disk_used,
             disk_total,
+            disks,
+            cpu_usage,
+            temperature_c,
+            network,
         }
     }
 }
 
+/// Sample real CPU utilization: `sysinfo` needs two refreshes spaced at
+/// least `MINIMUM_CPU_UPDATE_INTERVAL` apart to produce a meaningful delta.
+fn sample_cpu_usage(sys: &mut System) -> f32 {
+    sys.refresh_cpu();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    sys.refresh_cpu();
+    sys.global_cpu_info().cpu_usage()
+}
+
 /// Format uptime in human readable format
 fn format_uptime(seconds: u64) -> String {
     let days = seconds / 86400;
@@ -285,10 +430,140 @@ fn pixmap_to_rgba(pixmap: &Pixmap) -> Result<RgbaImage, String> {
     RgbaImage::from_raw(width, height, raw).ok_or_else(|| "Failed to build RGBA image".to_string())
 }
 
-fn print_ferris(image: &DynamicImage, max_width: Option<u32>) -> Result<(u32, u32), String> {
+/// Which graphics protocol the host terminal can render Ferris with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphicsCapability {
+    Kitty,
+    ITerm,
+    Sixel,
+    Blocks,
+}
+
+/// `--image-protocol` override; `Auto` triggers runtime detection.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ImageProtocol {
+    Auto,
+    Kitty,
+    Iterm,
+    Sixel,
+    Blocks,
+}
+
+impl ImageProtocol {
+    fn resolve(self) -> GraphicsCapability {
+        match self {
+            ImageProtocol::Auto => detect_graphics_capability(),
+            ImageProtocol::Kitty => GraphicsCapability::Kitty,
+            ImageProtocol::Iterm => GraphicsCapability::ITerm,
+            ImageProtocol::Sixel => GraphicsCapability::Sixel,
+            ImageProtocol::Blocks => GraphicsCapability::Blocks,
+        }
+    }
+}
+
+/// Detect the host terminal's graphics capability at runtime instead of
+/// guessing from the target OS. Kitty and iTerm2 identify themselves via
+/// environment variables; everything else is probed with a Device
+/// Attributes (DA1) query, whose response advertises Sixel support as
+/// attribute `4`.
+fn detect_graphics_capability() -> GraphicsCapability {
+    if env::var_os("KITTY_WINDOW_ID").is_some() {
+        return GraphicsCapability::Kitty;
+    }
+    if env::var("TERM_PROGRAM").is_ok_and(|v| v == "iTerm.app") {
+        return GraphicsCapability::ITerm;
+    }
+    if !io::stdout().is_terminal() {
+        return GraphicsCapability::Blocks;
+    }
+
+    match query_device_attributes() {
+        Some(response) if da_response_has_sixel(&response) => GraphicsCapability::Sixel,
+        _ => GraphicsCapability::Blocks,
+    }
+}
+
+/// Parse a DA1 response of the form `ESC [ ? Pn ; Pn ; ... c` and check
+/// whether attribute `4` (Sixel graphics) is present.
+fn da_response_has_sixel(response: &str) -> bool {
+    response
+        .trim_start_matches("\u{1b}[?")
+        .trim_end_matches('c')
+        .split(';')
+        .any(|attr| attr == "4")
+}
+
+/// Write a DA1 request (`ESC [ c`) to stdout and read the terminal's reply
+/// off stdin, bailing out after a short timeout for terminals that never
+/// answer. Runs in raw mode so the reply isn't line-buffered or echoed.
+fn query_device_attributes() -> Option<String> {
+    crossterm::terminal::enable_raw_mode().ok()?;
+    let wrote = write!(io::stdout(), "\x1b[c").and_then(|_| io::stdout().flush());
+    let response = if wrote.is_ok() {
+        read_da_response_with_deadline(Duration::from_millis(200))
+    } else {
+        None
+    };
+    let _ = crossterm::terminal::disable_raw_mode();
+    response
+}
+
+/// Read a DA1 reply off stdin, bailing out once `timeout` elapses instead of
+/// blocking indefinitely on terminals that never answer. Puts stdin in
+/// non-blocking mode for the duration of the read and restores its flags
+/// before returning, so nothing is left parked on the fd afterwards.
+#[cfg(unix)]
+fn read_da_response_with_deadline(timeout: Duration) -> Option<String> {
+    use std::os::unix::io::AsRawFd;
+
+    let fd = io::stdin().as_raw_fd();
+    let original_flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+    if original_flags == -1
+        || unsafe { libc::fcntl(fd, libc::F_SETFL, original_flags | libc::O_NONBLOCK) } == -1
+    {
+        return None;
+    }
+
+    let deadline = Instant::now() + timeout;
+    let mut response = Vec::new();
+    let mut byte = 0u8;
+    while Instant::now() < deadline && response.len() < 64 {
+        let read = unsafe { libc::read(fd, &mut byte as *mut u8 as *mut libc::c_void, 1) };
+        if read == 1 {
+            response.push(byte);
+            if byte == b'c' {
+                break;
+            }
+        } else {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    unsafe {
+        libc::fcntl(fd, libc::F_SETFL, original_flags);
+    }
+
+    if response.is_empty() {
+        None
+    } else {
+        Some(String::from_utf8_lossy(&response).into_owned())
+    }
+}
+
+#[cfg(not(unix))]
+fn read_da_response_with_deadline(_timeout: Duration) -> Option<String> {
+    None
+}
+
+fn print_ferris(
+    image: &DynamicImage,
+    max_width: Option<u32>,
+    capability: GraphicsCapability,
+) -> Result<(u32, u32), String> {
     let mut config = viuer::Config {
-        use_kitty: !cfg!(windows),
-        use_iterm: !cfg!(windows),
+        use_kitty: capability == GraphicsCapability::Kitty,
+        use_iterm: capability == GraphicsCapability::ITerm,
+        use_sixel: capability == GraphicsCapability::Sixel,
         ..Default::default()
     };
     config.transparent = true;
@@ -338,8 +613,13 @@ fn print_lines_with_offset(lines: &[String], offset: u16, art_height: u32) -> Re
     Ok(())
 }
 
-/// Print system information with Ferris art
-fn print_info(info: &SysInfo, args: &Args, theme: &Theme) {
+/// Build the (label, value) rows to render for the configured fields.
+fn build_lines(
+    info: &SysInfo,
+    args: &Args,
+    theme: &Theme,
+    fields: &[Field],
+) -> Vec<(String, String)> {
     let mut lines: Vec<(String, String)> = Vec::new();
     let (term_cols, _) = terminal_size();
     let art: &[&str] = if args.minimal {
@@ -397,83 +677,176 @@ fn print_info(info: &SysInfo, args: &Args, theme: &Theme) {
         }
     };
 
-    lines.push((label_color("OS"), value_color(&info.os)));
-    lines.push((label_color("Kernel"), value_color(&info.kernel)));
-    lines.push((label_color("Uptime"), value_color(&info.uptime)));
-    lines.push((label_color("Shell"), value_color(&info.shell)));
-
-    if !args.minimal {
-        // CPU info with cores
-        let cpu_suffix = format!(" ({} cores)", info.cpu_cores);
-        let max_cpu_len = info_cols
-            .saturating_sub("CPU: ".len() + cpu_suffix.chars().count())
-            .max(4);
-        let cpu_display = if info.cpu.chars().count() > max_cpu_len {
-            let mut trimmed = info
-                .cpu
-                .chars()
-                .take(max_cpu_len.saturating_sub(3))
-                .collect::<String>();
-            trimmed.push_str("...");
-            trimmed
-        } else {
-            info.cpu.clone()
-        };
-        let cpu_line = format!("{cpu_display}{cpu_suffix}");
-        lines.push((label_color("CPU"), value_color(&cpu_line)));
-
-        // Memory with bar
-        let mem_info = format!(
-            "{} / {} {}",
-            format_bytes(info.memory_used),
-            format_bytes(info.memory_total),
-            progress_bar(
-                info.memory_used,
-                info.memory_total,
-                10,
-                theme,
-                args.no_color
-            )
-        );
-        lines.push((label_color("Memory"), mem_info));
-    }
-
-    // Empty line before color blocks
-    lines.push(("".to_string(), "".to_string()));
-
-    // Color palette
-    if !args.minimal && !args.no_color {
-        let palette: String = [
-            Color::Black,
-            Color::Red,
-            Color::Green,
-            Color::Yellow,
-            Color::Blue,
-            Color::Magenta,
-            Color::Cyan,
-            Color::White,
-        ]
-        .iter()
-        .map(|c| "███".color(*c).to_string())
-        .collect();
-        lines.push(("".to_string(), palette));
-
-        let bright_palette: String = [
-            Color::BrightBlack,
-            Color::BrightRed,
-            Color::BrightGreen,
-            Color::BrightYellow,
-            Color::BrightBlue,
-            Color::BrightMagenta,
-            Color::BrightCyan,
-            Color::BrightWhite,
-        ]
-        .iter()
-        .map(|c| "███".color(*c).to_string())
-        .collect();
-        lines.push(("".to_string(), bright_palette));
+    // Fields the user wants shown, in the order they want them. `--minimal`
+    // always wins over the configured layout and trims to the terse set.
+    let active_fields: Vec<Field> = if args.minimal {
+        fields
+            .iter()
+            .copied()
+            .filter(|f| matches!(f, Field::Os | Field::Kernel | Field::Uptime | Field::Shell))
+            .collect()
+    } else {
+        fields.to_vec()
+    };
+
+    for field in &active_fields {
+        match field {
+            Field::Os => lines.push((label_color("OS"), value_color(&info.os))),
+            Field::Kernel => lines.push((label_color("Kernel"), value_color(&info.kernel))),
+            Field::Uptime => lines.push((label_color("Uptime"), value_color(&info.uptime))),
+            Field::Shell => lines.push((label_color("Shell"), value_color(&info.shell))),
+            Field::Cpu => {
+                let cpu_suffix = format!(" ({} cores)", info.cpu_cores);
+                let max_cpu_len = info_cols
+                    .saturating_sub("CPU: ".len() + cpu_suffix.chars().count())
+                    .max(4);
+                let cpu_display = if info.cpu.chars().count() > max_cpu_len {
+                    let mut trimmed = info
+                        .cpu
+                        .chars()
+                        .take(max_cpu_len.saturating_sub(3))
+                        .collect::<String>();
+                    trimmed.push_str("...");
+                    trimmed
+                } else {
+                    info.cpu.clone()
+                };
+                let cpu_line = format!(
+                    "{} {}",
+                    value_color(&format!("{cpu_display}{cpu_suffix}")),
+                    progress_bar(info.cpu_usage.round() as u64, 100, 10, theme, args.no_color)
+                );
+                lines.push((label_color("CPU"), cpu_line));
+            }
+            Field::Memory => {
+                let mem_info = format!(
+                    "{} / {} {}",
+                    format_bytes(info.memory_used),
+                    format_bytes(info.memory_total),
+                    progress_bar(
+                        info.memory_used,
+                        info.memory_total,
+                        10,
+                        theme,
+                        args.no_color
+                    )
+                );
+                lines.push((label_color("Memory"), mem_info));
+            }
+            Field::Disk => {
+                if args.disks {
+                    for disk in &info.disks {
+                        let disk_info = format!(
+                            "{} / {} {} ({})",
+                            format_bytes(disk.used),
+                            format_bytes(disk.total),
+                            progress_bar(disk.used, disk.total, 10, theme, args.no_color),
+                            disk.filesystem
+                        );
+                        lines.push((label_color(&disk.mount_point), disk_info));
+                    }
+                } else {
+                    let disk_info = format!(
+                        "{} / {} {}",
+                        format_bytes(info.disk_used),
+                        format_bytes(info.disk_total),
+                        progress_bar(info.disk_used, info.disk_total, 10, theme, args.no_color)
+                    );
+                    lines.push((label_color("Disk"), disk_info));
+                }
+            }
+            Field::Temp => {
+                if args.temps
+                    && let Some(temp) = info.temperature_c
+                {
+                    let reading = format!("{temp:.1}°C");
+                    let colored_reading = if args.no_color {
+                        reading
+                    } else {
+                        let color = if temp > 80.0 {
+                            Color::Red
+                        } else if temp > 60.0 {
+                            Color::Yellow
+                        } else {
+                            theme.accent
+                        };
+                        reading.color(color).to_string()
+                    };
+                    lines.push((label_color("Temp"), colored_reading));
+                }
+            }
+            Field::Network => {
+                if args.network
+                    && let Some((rx, tx)) = info.network
+                {
+                    let net_info = format!("↓ {} / ↑ {}", format_bytes(rx), format_bytes(tx));
+                    lines.push((label_color("Network"), value_color(&net_info)));
+                }
+            }
+            Field::Palette => {
+                lines.push(("".to_string(), "".to_string()));
+
+                if !args.no_color {
+                    let palette: String = [
+                        Color::Black,
+                        Color::Red,
+                        Color::Green,
+                        Color::Yellow,
+                        Color::Blue,
+                        Color::Magenta,
+                        Color::Cyan,
+                        Color::White,
+                    ]
+                    .iter()
+                    .map(|c| "███".color(*c).to_string())
+                    .collect();
+                    lines.push(("".to_string(), palette));
+
+                    let bright_palette: String = [
+                        Color::BrightBlack,
+                        Color::BrightRed,
+                        Color::BrightGreen,
+                        Color::BrightYellow,
+                        Color::BrightBlue,
+                        Color::BrightMagenta,
+                        Color::BrightCyan,
+                        Color::BrightWhite,
+                    ]
+                    .iter()
+                    .map(|c| "███".color(*c).to_string())
+                    .collect();
+                    lines.push(("".to_string(), bright_palette));
+                }
+            }
+        }
     }
 
+    lines
+}
+
+/// Render previously built (label, value) rows alongside the Ferris art,
+/// falling back to plain side-by-side printing when the terminal can't fit
+/// or render the image. `cached_image` lets `--watch` redraw each tick
+/// without re-decoding the SVG every frame.
+fn render_lines(
+    lines: &[(String, String)],
+    args: &Args,
+    theme: &Theme,
+    cached_image: Option<&DynamicImage>,
+    capability: GraphicsCapability,
+) {
+    let (term_cols, _) = terminal_size();
+    let art: &[&str] = if args.minimal {
+        FERRIS_SMALL
+    } else {
+        FERRIS_ART
+    };
+    let art_width = if args.no_art {
+        0usize
+    } else {
+        art.iter().map(|l| l.len()).max().unwrap_or(0)
+    };
+
     let line_strings: Vec<String> = lines
         .iter()
         .map(|(label, value)| {
@@ -500,8 +873,8 @@ fn print_info(info: &SysInfo, args: &Args, theme: &Theme) {
     if !args.no_art
         && !args.minimal
         && let Some(max_width) = max_image_width
-        && let Ok(image) = render_ferris()
-        && let Ok((width, height)) = print_ferris(&image, Some(max_width))
+        && let Ok(image) = cached_image.cloned().map(Ok).unwrap_or_else(render_ferris)
+        && let Ok((width, height)) = print_ferris(&image, Some(max_width), capability)
     {
         let offset = width
             .saturating_add(2)
@@ -543,20 +916,256 @@ fn print_info(info: &SysInfo, args: &Args, theme: &Theme) {
     }
 }
 
+/// Print system information with Ferris art
+fn print_info(info: &SysInfo, args: &Args, theme: &Theme, fields: &[Field]) {
+    let lines = build_lines(info, args, theme, fields);
+    // Only probe the terminal when art is actually going to be drawn — the
+    // DA1 query toggles raw mode and can block for up to 200ms.
+    let capability = if args.no_art || args.minimal {
+        GraphicsCapability::Blocks
+    } else {
+        args.image_protocol.resolve()
+    };
+    render_lines(&lines, args, theme, None, capability);
+}
+
+/// Re-render the fetch output on a timer until interrupted. Uses the
+/// alternate screen so the user's scrollback isn't spammed with old frames,
+/// and decodes the Ferris SVG only once up front.
+fn run_watch(interval_ms: u64, args: &Args, theme: &Theme, fields: &[Field]) -> Result<(), String> {
+    // Detect the image protocol before entering the alternate screen: probing
+    // toggles raw mode on its own and we don't want that to interfere with
+    // the raw mode the watch loop holds for the rest of the session. Only
+    // probe when art will actually be drawn.
+    let capability = if args.no_art || args.minimal {
+        GraphicsCapability::Blocks
+    } else {
+        args.image_protocol.resolve()
+    };
+
+    let mut stdout = io::stdout();
+    let entered = execute!(
+        stdout,
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::cursor::Hide
+    )
+    .map_err(|err: io::Error| err.to_string())
+    .and_then(|()| crossterm::terminal::enable_raw_mode().map_err(|err| err.to_string()));
+
+    // However setup failed, always run the cleanup below before returning so
+    // a failed enable_raw_mode() (e.g. no controlling TTY) can't leave the
+    // terminal stuck in the alternate screen with the cursor hidden.
+    let result = entered.and_then(|()| watch_loop(interval_ms, args, theme, fields, capability));
+
+    let _ = crossterm::terminal::disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        crossterm::cursor::Show,
+        crossterm::terminal::LeaveAlternateScreen
+    );
+    result
+}
+
+fn watch_loop(
+    interval_ms: u64,
+    args: &Args,
+    theme: &Theme,
+    fields: &[Field],
+    capability: GraphicsCapability,
+) -> Result<(), String> {
+    let cached_image = if args.no_art || args.minimal {
+        None
+    } else {
+        render_ferris().ok()
+    };
+
+    loop {
+        let info = SysInfo::collect(args.temps, args.network);
+
+        execute!(
+            io::stdout(),
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            crossterm::cursor::MoveTo(0, 0)
+        )
+        .map_err(|err: io::Error| err.to_string())?;
+
+        let lines = build_lines(&info, args, theme, fields);
+        render_lines(&lines, args, theme, cached_image.as_ref(), capability);
+
+        if wait_for_tick_or_interrupt(Duration::from_millis(interval_ms))? {
+            return Ok(());
+        }
+    }
+}
+
+/// Sleep for `duration`, polling for a Ctrl-C / Esc keypress so `--watch`
+/// can exit promptly instead of only on the next tick boundary.
+fn wait_for_tick_or_interrupt(duration: Duration) -> Result<bool, String> {
+    let deadline = Instant::now() + duration;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Ok(false);
+        }
+
+        let poll_window = remaining.min(Duration::from_millis(50));
+        if crossterm::event::poll(poll_window).map_err(|err| err.to_string())?
+            && let crossterm::event::Event::Key(key) =
+                crossterm::event::read().map_err(|err| err.to_string())?
+        {
+            let is_ctrl_c = key.code == crossterm::event::KeyCode::Char('c')
+                && key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL);
+            if is_ctrl_c || key.code == crossterm::event::KeyCode::Esc {
+                return Ok(true);
+            }
+        }
+    }
+}
+
 fn main() {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
-    // Disable colors if requested or if terminal doesn't support it
-    if args.no_color {
-        colored::control::set_override(false);
+    // Resolve the effective color setting from --color, --no-color, and the
+    // environment, then feed it through both the global override (so `colored`
+    // helpers like the palette blocks react) and `args.no_color` (which the
+    // rest of `print_info` branches on directly).
+    args.no_color = resolve_no_color(&args);
+    colored::control::set_override(!args.no_color);
+
+    // Load the user config, if any, so it can override the theme palette and
+    // info field layout below. CLI flags (e.g. `--theme`) still win.
+    let user_config =
+        UserConfig::locate(args.config.as_deref()).and_then(|path| match UserConfig::load(&path) {
+            Ok(config) => Some(config),
+            Err(err) => {
+                eprintln!("warning: {err}");
+                None
+            }
+        });
+
+    // Get theme, layering any configured palette overrides on top unless
+    // `--theme` was given on the command line
+    let theme_from_cli = <Args as clap::CommandFactory>::command()
+        .get_matches()
+        .value_source("theme")
+        == Some(clap::parser::ValueSource::CommandLine);
+    let mut theme = Theme::get(&args.theme);
+    if !theme_from_cli
+        && let Some(palette) = user_config.as_ref().and_then(|c| c.palette.as_ref())
+    {
+        theme = palette.apply(theme);
     }
 
-    // Get theme
-    let theme = Theme::get(&args.theme);
+    let fields: Vec<Field> = user_config
+        .as_ref()
+        .and_then(|c| c.fields.clone())
+        .unwrap_or_else(|| Field::DEFAULT_ORDER.to_vec());
+
+    if let Some(interval_ms) = args.watch {
+        if let Err(err) = run_watch(interval_ms, &args, &theme, &fields) {
+            eprintln!("error: {err}");
+        }
+        return;
+    }
 
     // Collect system info
-    let info = SysInfo::collect();
+    let info = SysInfo::collect(args.temps, args.network);
 
     // Print the info
-    print_info(&info, &args, &theme);
+    print_info(&info, &args, &theme, &fields);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Guards tests that mutate process-wide env vars so they don't race
+    // each other under the default parallel test runner.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn args_with(extra: &[&str]) -> Args {
+        let mut argv = vec!["ferris-fetch"];
+        argv.extend_from_slice(extra);
+        Args::parse_from(argv)
+    }
+
+    #[test]
+    fn resolve_no_color_deprecated_flag_wins() {
+        let args = args_with(&["--no-color", "--color", "always"]);
+        assert!(resolve_no_color(&args));
+    }
+
+    #[test]
+    fn resolve_no_color_always_overrides_auto_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let prev = env::var_os("NO_COLOR");
+        unsafe {
+            env::set_var("NO_COLOR", "1");
+        }
+        let args = args_with(&["--color", "always"]);
+        assert!(!resolve_no_color(&args));
+        unsafe {
+            match prev {
+                Some(value) => env::set_var("NO_COLOR", value),
+                None => env::remove_var("NO_COLOR"),
+            }
+        }
+    }
+
+    #[test]
+    fn resolve_no_color_never_ignores_clicolor_force() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let prev = env::var_os("CLICOLOR_FORCE");
+        unsafe {
+            env::set_var("CLICOLOR_FORCE", "1");
+        }
+        let args = args_with(&["--color", "never"]);
+        assert!(resolve_no_color(&args));
+        unsafe {
+            match prev {
+                Some(value) => env::set_var("CLICOLOR_FORCE", value),
+                None => env::remove_var("CLICOLOR_FORCE"),
+            }
+        }
+    }
+
+    #[test]
+    fn resolve_no_color_auto_respects_no_color_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let prev_no_color = env::var_os("NO_COLOR");
+        let prev_force = env::var_os("CLICOLOR_FORCE");
+        unsafe {
+            env::remove_var("CLICOLOR_FORCE");
+            env::set_var("NO_COLOR", "1");
+        }
+        let args = args_with(&[]);
+        assert!(resolve_no_color(&args));
+        unsafe {
+            match prev_no_color {
+                Some(value) => env::set_var("NO_COLOR", value),
+                None => env::remove_var("NO_COLOR"),
+            }
+            if let Some(value) = prev_force {
+                env::set_var("CLICOLOR_FORCE", value);
+            }
+        }
+    }
+
+    #[test]
+    fn da_response_has_sixel_detects_attribute_four() {
+        assert!(da_response_has_sixel("\u{1b}[?62;1;4;6c"));
+    }
+
+    #[test]
+    fn da_response_has_sixel_rejects_missing_attribute() {
+        assert!(!da_response_has_sixel("\u{1b}[?62;1;6c"));
+    }
+
+    #[test]
+    fn da_response_has_sixel_rejects_empty_response() {
+        assert!(!da_response_has_sixel(""));
+    }
 }